@@ -0,0 +1,109 @@
+use std::fmt;
+use std::fs::{self, DirEntry};
+use std::io;
+use std::mem;
+use std::path::Path;
+
+use futures::{Async, Future, Poll, Stream};
+use futures::future::lazy;
+use futures::sync::oneshot;
+
+use FsPool;
+use FsFuture;
+
+pub fn new<P>(pool: &FsPool, path: P) -> FsReadDir
+where
+    P: AsRef<Path> + Send + 'static,
+{
+    let (tx, rx) = oneshot::channel();
+
+    let fut = Box::new(lazy(move || {
+        let res = fs::read_dir(path).map_err(From::from);
+
+        tx.send(res).map_err(|_| ())
+    }));
+
+    pool.executor.execute(fut).unwrap();
+
+    FsReadDir {
+        pool: pool.clone(),
+        state: State::Opening(super::fs(rx)),
+    }
+}
+
+/// A `Stream` of the entries in a target directory.
+pub struct FsReadDir {
+    pool: FsPool,
+    state: State,
+}
+
+enum State {
+    Opening(FsFuture<fs::ReadDir>),
+    Idle(fs::ReadDir),
+    Reading(FsFuture<(fs::ReadDir, Option<io::Result<DirEntry>>)>),
+    Done,
+    Swapping,
+}
+
+impl Stream for FsReadDir {
+    type Item = DirEntry;
+    type Error = io::Error;
+
+    fn poll(&mut self) -> Poll<Option<Self::Item>, Self::Error> {
+        loop {
+            match mem::replace(&mut self.state, State::Swapping) {
+                State::Opening(mut rx) => {
+                    let polled = rx.poll();
+                    self.state = State::Opening(rx);
+                    let dir = try_ready!(polled);
+
+                    self.state = State::Idle(dir);
+                }
+                State::Idle(mut dir) => {
+                    let (tx, rx) = oneshot::channel();
+
+                    let fut = Box::new(lazy(move || {
+                        let next = dir.next().map(|res| res.map_err(io::Error::from));
+
+                        tx.send(Ok((dir, next))).map_err(|_| ())
+                    }));
+
+                    self.pool.executor.execute(fut).unwrap();
+
+                    self.state = State::Reading(super::fs(rx));
+                }
+                State::Reading(mut rx) => {
+                    let polled = rx.poll();
+                    self.state = State::Reading(rx);
+                    let (dir, next) = try_ready!(polled);
+
+                    match next {
+                        Some(Ok(entry)) => {
+                            self.state = State::Idle(dir);
+                            return Ok(Async::Ready(Some(entry)));
+                        }
+                        Some(Err(e)) => {
+                            self.state = State::Idle(dir);
+                            return Err(e);
+                        }
+                        None => {
+                            self.state = State::Done;
+                            return Ok(Async::Ready(None));
+                        }
+                    }
+                }
+                State::Done => {
+                    self.state = State::Done;
+                    return Ok(Async::Ready(None));
+                }
+                State::Swapping => unreachable!(),
+            }
+        }
+    }
+}
+
+impl fmt::Debug for FsReadDir {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("FsReadDir").finish()
+    }
+}
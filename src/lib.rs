@@ -30,31 +30,47 @@
 //! # }
 //! # fn main() {}
 //! ```
+//!
+//! # `io-uring`
+//!
+//! On Linux, enabling the `io-uring` cargo feature and constructing the pool
+//! with `FsPool::new_io_uring` submits reads and writes directly to an
+//! `io_uring` ring instead of handing them off to a thread pool worker.
 
 extern crate bytes;
 #[macro_use]
 extern crate futures;
 extern crate futures_cpupool;
+extern crate libc;
+#[cfg(feature = "io-uring")]
+extern crate rio;
 
 use std::{fmt, fs, io};
 use std::path::Path;
 use std::sync::Arc;
 
+use bytes::Bytes;
 use futures::{Async, Future, Poll};
 use futures::future::{lazy, Executor};
 use futures::sync::oneshot::{self, Receiver};
 use futures_cpupool::CpuPool;
 
+pub use self::dir::FsReadDir;
 pub use self::read::{FsReadStream, ReadOptions};
-pub use self::write::{FsWriteSink, WriteOptions};
+pub use self::write::{FsSync, FsWriteSink, WriteOptions};
 
+mod dir;
 mod read;
+#[cfg(feature = "io-uring")]
+mod uring;
 mod write;
 
 /// A pool of threads to handle file IO.
 #[derive(Clone)]
 pub struct FsPool {
-    executor: Arc<Executor<Box<Future<Item = (), Error = ()> + Send>>>,
+    executor: Arc<Executor<Box<dyn Future<Item = (), Error = ()> + Send>>>,
+    #[cfg(feature = "io-uring")]
+    ring: Option<Arc<uring::Ring>>,
 }
 
 impl FsPool {
@@ -62,16 +78,36 @@ impl FsPool {
     pub fn new(threads: usize) -> Self {
         FsPool {
             executor: Arc::new(CpuPool::new(threads)),
+            #[cfg(feature = "io-uring")]
+            ring: None,
         }
     }
 
     /// Creates a new `FsPool`, from an existing `Executor`.
     pub fn from_executor<E>(executor: E) -> Self
     where
-        E: Executor<Box<Future<Item = (), Error = ()> + Send>> + Clone + 'static,
+        E: Executor<Box<dyn Future<Item = (), Error = ()> + Send>> + Clone + 'static,
     {
         FsPool {
             executor: Arc::new(executor),
+            #[cfg(feature = "io-uring")]
+            ring: None,
+        }
+    }
+
+    /// Creates a new `FsPool` backed by a Linux `io_uring` ring, with `threads`
+    /// kept around as a fallback thread pool.
+    ///
+    /// Reads and writes are submitted directly to the ring instead of hopping
+    /// onto a worker thread. If the ring can't be set up (e.g. the kernel is
+    /// too old, or this isn't Linux), this falls back to a plain thread pool
+    /// of `threads` workers, same as `FsPool::new`.
+    #[cfg(feature = "io-uring")]
+    pub fn new_io_uring(threads: usize) -> Self {
+        let ring = uring::Ring::new().ok().map(Arc::new);
+        FsPool {
+            executor: Arc::new(CpuPool::new(threads)),
+            ring,
         }
     }
 
@@ -103,6 +139,33 @@ impl FsPool {
         ::write::new_from_file(self, file)
     }
 
+    /// Returns a `Future` that resolves with the contents of the file at
+    /// the supplied path, read into a single `Bytes`.
+    ///
+    /// Reading starts at `opts`'s offset and stops at its length limit, or
+    /// at EOF if no length is set.
+    pub fn read_into_bytes<P: AsRef<Path> + Send + 'static>(
+        &self,
+        path: P,
+        opts: ReadOptions,
+    ) -> FsFuture<Bytes> {
+        ::read::read_into_bytes(self, path, opts)
+    }
+
+    /// Returns a `Future` that resolves with the metadata of the file at
+    /// the supplied path.
+    pub fn metadata<P: AsRef<Path> + Send + 'static>(&self, path: P) -> FsFuture<fs::Metadata> {
+        let (tx, rx) = oneshot::channel();
+
+        let fut = Box::new(lazy(move || {
+            tx.send(fs::metadata(path).map_err(From::from)).map_err(|_| ())
+        }));
+
+        self.executor.execute(fut).unwrap();
+
+        fs(rx)
+    }
+
     /// Returns a `Future` that resolves when the target file is deleted.
     pub fn delete<P: AsRef<Path> + Send + 'static>(&self, path: P) -> FsFuture<()> {
         let (tx, rx) = oneshot::channel();
@@ -116,6 +179,89 @@ impl FsPool {
 
         fs(rx)
     }
+
+    /// Returns a `Future` that resolves with the number of bytes copied,
+    /// once `from` has been copied to `to`.
+    pub fn copy<P1, P2>(&self, from: P1, to: P2) -> FsFuture<u64>
+    where
+        P1: AsRef<Path> + Send + 'static,
+        P2: AsRef<Path> + Send + 'static,
+    {
+        let (tx, rx) = oneshot::channel();
+
+        let fut = Box::new(lazy(move || {
+            tx.send(fs::copy(from, to).map_err(From::from)).map_err(|_| ())
+        }));
+
+        self.executor.execute(fut).unwrap();
+
+        fs(rx)
+    }
+
+    /// Returns a `Future` that resolves once `from` has been renamed to `to`.
+    pub fn rename<P1, P2>(&self, from: P1, to: P2) -> FsFuture<()>
+    where
+        P1: AsRef<Path> + Send + 'static,
+        P2: AsRef<Path> + Send + 'static,
+    {
+        let (tx, rx) = oneshot::channel();
+
+        let fut = Box::new(lazy(move || {
+            tx.send(fs::rename(from, to).map_err(From::from)).map_err(|_| ())
+        }));
+
+        self.executor.execute(fut).unwrap();
+
+        fs(rx)
+    }
+
+    /// Returns a `Future` that resolves once the directory at `path`, and
+    /// any of its missing parent directories, have been created.
+    pub fn create_dir_all<P: AsRef<Path> + Send + 'static>(&self, path: P) -> FsFuture<()> {
+        let (tx, rx) = oneshot::channel();
+
+        let fut = Box::new(lazy(move || {
+            tx.send(fs::create_dir_all(path).map_err(From::from))
+                .map_err(|_| ())
+        }));
+
+        self.executor.execute(fut).unwrap();
+
+        fs(rx)
+    }
+
+    /// Returns a `Stream` of the entries in the directory at `path`.
+    pub fn read_dir<P: AsRef<Path> + Send + 'static>(&self, path: P) -> FsReadDir {
+        ::dir::new(self, path)
+    }
+
+    /// Returns a `Future` that resolves once `file`'s data and metadata
+    /// have been flushed to disk.
+    pub fn sync_all(&self, file: fs::File) -> FsFuture<()> {
+        let (tx, rx) = oneshot::channel();
+
+        let fut = Box::new(lazy(move || {
+            tx.send(file.sync_all().map_err(From::from)).map_err(|_| ())
+        }));
+
+        self.executor.execute(fut).unwrap();
+
+        fs(rx)
+    }
+
+    /// Returns a `Future` that resolves once `file`'s data, but not
+    /// necessarily its metadata, has been flushed to disk.
+    pub fn sync_data(&self, file: fs::File) -> FsFuture<()> {
+        let (tx, rx) = oneshot::channel();
+
+        let fut = Box::new(lazy(move || {
+            tx.send(file.sync_data().map_err(From::from)).map_err(|_| ())
+        }));
+
+        self.executor.execute(fut).unwrap();
+
+        fs(rx)
+    }
 }
 
 impl Default for FsPool {
@@ -1,6 +1,6 @@
 use std::{cmp, fmt, mem};
 use std::fs::{File, Metadata};
-use std::io::{self, Read};
+use std::io::{self, Read, Seek, SeekFrom};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
@@ -16,18 +16,43 @@ const BUF_SIZE: usize = 8192;
 
 /// Options for how to read the file.
 ///
-/// The default is to automatically determine the buffer size.
+/// The default is to automatically determine the buffer size, and to read
+/// the whole file from the start.
 #[derive(Debug)]
 pub struct ReadOptions {
     /// The buffer size to use.
     ///
     /// If set to `None`, this is automatically determined from the operating system.
     buffer_size: Option<usize>,
+    /// The byte offset to start reading from.
+    offset: u64,
+    /// The maximum number of bytes to read, starting at `offset`.
+    ///
+    /// If set to `None`, the file is read until EOF.
+    length: Option<u64>,
+}
+
+impl ReadOptions {
+    /// Sets the byte offset to start reading from.
+    pub fn offset(mut self, offset: u64) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Sets the maximum number of bytes to read, starting at the offset.
+    pub fn length(mut self, length: u64) -> Self {
+        self.length = Some(length);
+        self
+    }
 }
 
 impl Default for ReadOptions {
     fn default() -> ReadOptions {
-        ReadOptions { buffer_size: None }
+        ReadOptions {
+            buffer_size: None,
+            offset: 0,
+            length: None,
+        }
     }
 }
 
@@ -37,20 +62,49 @@ where
 {
     FsReadStream {
         buffer: BytesMut::with_capacity(0),
+        length: opts.length,
         //TODO: can we adjust bounds, since this is making an owned copy anyways?
         path: Arc::new(path.as_ref().to_owned()),
         pool: pool.clone(),
-        state: State::Init(opts.buffer_size),
+        pos: opts.offset,
+        remaining: opts.length,
+        state: State::Init(opts.buffer_size, opts.offset),
     }
 }
 
-pub fn new_from_file(pool: &FsPool, file: File, opts: ReadOptions) -> FsReadStream {
-    let final_buf_size = finalize_buf_size(opts.buffer_size, &file);
+/// Reads the whole file at `path` into a single `Bytes`, honoring `opts`.
+pub fn read_into_bytes<P>(pool: &FsPool, path: P, opts: ReadOptions) -> FsFuture<Bytes>
+where
+    P: AsRef<Path> + Send + 'static,
+{
+    let (tx, rx) = oneshot::channel();
+
+    let fut = Box::new(lazy(move || {
+        let res = open_and_read_to_end(path.as_ref(), opts).map_err(From::from);
+
+        tx.send(res).map_err(|_| ())
+    }));
+
+    pool.executor.execute(fut).unwrap();
+
+    super::fs(rx)
+}
+
+pub fn new_from_file(pool: &FsPool, mut file: File, opts: ReadOptions) -> FsReadStream {
+    if opts.offset != 0 {
+        // best effort; if this fails, the error is dropped and reading
+        // starts from wherever the file's cursor already is
+        let _ = file.seek(SeekFrom::Start(opts.offset));
+    }
+    let final_buf_size = finalize_buf_size(opts.buffer_size, &file, opts.offset);
     FsReadStream {
         buffer: BytesMut::with_capacity(0),
+        length: opts.length,
         //TODO: can we adjust bounds, since this is making an owned copy anyways?
         path: Arc::new(PathBuf::new()),
         pool: pool.clone(),
+        pos: opts.offset,
+        remaining: opts.length,
         state: State::Ready(file, final_buf_size),
     }
 }
@@ -58,35 +112,97 @@ pub fn new_from_file(pool: &FsPool, file: File, opts: ReadOptions) -> FsReadStre
 /// A `Stream` of bytes from a target file.
 pub struct FsReadStream {
     buffer: BytesMut,
+    /// The original `ReadOptions::length` limit, kept around so that
+    /// `seek` can restart the same budget relative to the new position.
+    length: Option<u64>,
     path: Arc<PathBuf>,
     pool: FsPool,
+    pos: u64,
+    remaining: Option<u64>,
     state: State,
 }
 
 enum State {
-    Init(Option<usize>),
+    Init(Option<usize>, u64),
     Opening(FsFuture<(File, BytesMut)>),
     Working(FsFuture<(File, BytesMut)>, usize),
+    #[cfg(feature = "io-uring")]
+    Submitted(Box<dyn Future<Item = (File, BytesMut), Error = io::Error> + Send>, usize),
+    Seeking(FsFuture<(File, usize, u64)>),
     Ready(File, usize),
     Eof,
     Swapping,
 }
 
 impl FsReadStream {
+    /// Repositions the stream, so that the next read starts from `pos`.
+    ///
+    /// This runs the seek on the pool, same as a read, so the stream won't
+    /// yield anything until it completes.
+    ///
+    /// # Panics
+    ///
+    /// This only makes sense to call between yields from the stream, i.e.
+    /// after `poll` has returned `Async::Ready(Some(_))` and before it's
+    /// polled again. Calling it while a read or another seek is already in
+    /// flight would otherwise either silently drop the request or race with
+    /// the pending operation, so it panics instead.
+    ///
+    /// It also panics when the stream holds no open file: before a stream
+    /// created from a path has yielded its first chunk, and once the stream
+    /// has reached its end, including when the chunk just yielded used up
+    /// the `ReadOptions::length` limit.
+    pub fn seek(&mut self, pos: SeekFrom) {
+        let (file, buf_size) = match mem::replace(&mut self.state, State::Swapping) {
+            State::Ready(file, buf_size) => (file, buf_size),
+            other => {
+                self.state = other;
+                panic!("FsReadStream::seek called while no file is ready to reposition");
+            }
+        };
+
+        self.buffer = BytesMut::with_capacity(0);
+
+        let (tx, rx) = oneshot::channel();
+
+        let fut = Box::new(lazy(move || {
+            let res = seek(file, pos, buf_size).map_err(From::from);
+
+            tx.send(res).map_err(|_| ())
+        }));
+
+        self.pool.executor.execute(fut).unwrap();
+
+        self.state = State::Seeking(super::fs(rx));
+    }
+
     fn handle_read(
         &mut self,
         file: File,
-        chunk: BytesMut,
+        mut chunk: BytesMut,
         buf_size: usize,
     ) -> Poll<Option<<Self as Stream>::Item>, <Self as Stream>::Error> {
         if chunk.is_empty() {
             self.state = State::Eof;
             return Ok(Async::Ready(None));
-        } else {
-            self.buffer = chunk;
-            self.state = State::Ready(file, buf_size);
-            return Ok(Async::Ready(Some(self.buffer.take().freeze())));
         }
+
+        self.pos += chunk.len() as u64;
+
+        if let Some(remaining) = self.remaining {
+            if (chunk.len() as u64) >= remaining {
+                chunk.truncate(remaining as usize);
+                self.remaining = Some(0);
+                self.buffer = chunk;
+                self.state = State::Eof;
+                return Ok(Async::Ready(Some(self.buffer.take().freeze())));
+            }
+            self.remaining = Some(remaining - chunk.len() as u64);
+        }
+
+        self.buffer = chunk;
+        self.state = State::Ready(file, buf_size);
+        return Ok(Async::Ready(Some(self.buffer.take().freeze())));
     }
 }
 
@@ -97,13 +213,13 @@ impl Stream for FsReadStream {
     fn poll(&mut self) -> Poll<Option<Self::Item>, Self::Error> {
         loop {
             match mem::replace(&mut self.state, State::Swapping) {
-                State::Init(buf_size) => {
+                State::Init(buf_size, offset) => {
                     let path = self.path.clone();
 
                     let (tx, rx) = oneshot::channel();
 
                     let fut = Box::new(lazy(move || {
-                        let res = open_and_read(&path, buf_size).map_err(From::from);
+                        let res = open_and_read(&path, buf_size, offset).map_err(From::from);
 
                         tx.send(res).map_err(|_| ())
                     }));
@@ -127,9 +243,34 @@ impl Stream for FsReadStream {
 
                     return self.handle_read(file, chunk, buf_size);
                 }
+                State::Seeking(mut rx) => {
+                    let polled = rx.poll();
+                    self.state = State::Seeking(rx);
+                    let (file, buf_size, pos) = try_ready!(polled);
+
+                    self.pos = pos;
+                    // restart the length budget relative to the new position,
+                    // rather than keep counting down from wherever it was
+                    // before the seek
+                    self.remaining = self.length;
+                    self.state = State::Ready(file, buf_size);
+                }
                 State::Ready(file, buf_size) => {
                     let buf = self.buffer.split_off(0);
 
+                    #[cfg(feature = "io-uring")]
+                    {
+                        if let Some(ref ring) = self.pool.ring {
+                            let mut buf = buf;
+                            if !buf.has_remaining_mut() {
+                                buf.reserve(buf_size);
+                            }
+                            let fut = ring.read_at(file, buf, self.pos);
+                            self.state = State::Submitted(Box::new(fut), buf_size);
+                            continue;
+                        }
+                    }
+
                     let (tx, rx) = oneshot::channel();
 
                     let fut = Box::new(lazy(move || {
@@ -142,6 +283,20 @@ impl Stream for FsReadStream {
 
                     self.state = State::Working(super::fs(rx), buf_size);
                 }
+                #[cfg(feature = "io-uring")]
+                State::Submitted(mut fut, buf_size) => {
+                    let polled = fut.poll();
+                    match polled {
+                        Ok(Async::NotReady) => {
+                            self.state = State::Submitted(fut, buf_size);
+                            return Ok(Async::NotReady);
+                        }
+                        Ok(Async::Ready((file, chunk))) => {
+                            return self.handle_read(file, chunk, buf_size);
+                        }
+                        Err(e) => return Err(e),
+                    }
+                }
                 State::Eof => {
                     self.state = State::Eof;
                     return Ok(Async::Ready(None));
@@ -169,22 +324,25 @@ fn read(mut file: File, buf_size: usize, mut buf: BytesMut) -> io::Result<(File,
     Ok((file, buf))
 }
 
-fn finalize_buf_size(buf_size: Option<usize>, file: &File) -> usize {
+fn finalize_buf_size(buf_size: Option<usize>, file: &File, offset: u64) -> usize {
     match file.metadata() {
         Ok(metadata) => {
             // try to get the buffer size from the OS if necessary
             let buf_size = buf_size.unwrap_or_else(|| get_block_size(&metadata));
 
             // if size is smaller than our chunk size, don't reserve wasted space
-            cmp::min(metadata.len() as usize, buf_size)
+            cmp::min(metadata.len().saturating_sub(offset) as usize, buf_size)
         }
         _ => buf_size.unwrap_or(BUF_SIZE),
     }
 }
 
-fn open_and_read(path: &Path, buf_size: Option<usize>) -> io::Result<(File, BytesMut)> {
-    let file = File::open(path)?;
-    let final_buf_size = finalize_buf_size(buf_size, &file);
+fn open_and_read(path: &Path, buf_size: Option<usize>, offset: u64) -> io::Result<(File, BytesMut)> {
+    let mut file = File::open(path)?;
+    if offset != 0 {
+        file.seek(SeekFrom::Start(offset))?;
+    }
+    let final_buf_size = finalize_buf_size(buf_size, &file, offset);
     read(
         file,
         final_buf_size,
@@ -192,6 +350,47 @@ fn open_and_read(path: &Path, buf_size: Option<usize>) -> io::Result<(File, Byte
     )
 }
 
+fn seek(mut file: File, pos: SeekFrom, buf_size: usize) -> io::Result<(File, usize, u64)> {
+    let new_pos = file.seek(pos)?;
+    Ok((file, buf_size, new_pos))
+}
+
+fn open_and_read_to_end(path: &Path, opts: ReadOptions) -> io::Result<Bytes> {
+    let mut file = File::open(path)?;
+    if opts.offset != 0 {
+        file.seek(SeekFrom::Start(opts.offset))?;
+    }
+
+    let chunk_size = finalize_buf_size(opts.buffer_size, &file, opts.offset);
+    let cap = match opts.length {
+        Some(len) => len as usize,
+        None => match file.metadata() {
+            Ok(metadata) => metadata.len().saturating_sub(opts.offset) as usize,
+            Err(_) => chunk_size,
+        },
+    };
+
+    let mut buf = BytesMut::with_capacity(cap);
+    loop {
+        if !buf.has_remaining_mut() {
+            buf.reserve(chunk_size);
+        }
+        let n = file.read(unsafe { buf.bytes_mut() })?;
+        if n == 0 {
+            break;
+        }
+        unsafe { buf.advance_mut(n) };
+
+        if let Some(len) = opts.length {
+            if buf.len() as u64 >= len {
+                buf.truncate(len as usize);
+                break;
+            }
+        }
+    }
+    Ok(buf.freeze())
+}
+
 #[cfg(unix)]
 fn get_block_size(metadata: &Metadata) -> usize {
     use std::os::unix::fs::MetadataExt;
@@ -0,0 +1,136 @@
+//! Linux `io_uring` backend for `FsPool`, enabled by the `io-uring` cargo feature.
+//!
+//! Reads and writes are submitted as SQEs against a shared `rio::Rio` ring and
+//! complete via the kernel's completion queue, instead of blocking a thread
+//! pool worker for the duration of the syscall.
+
+use std::fs::File;
+use std::io;
+use std::mem;
+
+use bytes::{BufMut, Bytes, BytesMut};
+use futures::{Future, Poll};
+
+/// A shared handle to an `io_uring` submission/completion ring.
+pub(crate) struct Ring {
+    rio: rio::Rio,
+}
+
+impl Ring {
+    /// Sets up a new ring.
+    ///
+    /// Fails if the running kernel doesn't support `io_uring`, or if setting
+    /// up the ring otherwise fails; callers should fall back to a thread
+    /// pool backed `FsPool` in that case.
+    pub(crate) fn new() -> io::Result<Ring> {
+        rio::new().map(|rio| Ring { rio })
+    }
+
+    /// Submits a `read_at` SQE for `file` at `offset`, reading into `buf`.
+    ///
+    /// Resolves to `file` and `buf` handed back, with `buf` advanced by the
+    /// number of bytes read.
+    pub(crate) fn read_at(&self, file: File, buf: BytesMut, offset: u64) -> ReadAt {
+        // `owned` keeps `file`/`buf` alive at a stable heap address for as
+        // long as the SQE referencing them is in flight. `rio::Rio::read_at`
+        // borrows both for the lifetime of the returned completion, so we
+        // can't simply move `file`/`buf` into a combinator the way the
+        // thread pool path does -- that borrow would need to outlive the
+        // values it points at. Erasing the borrow's lifetime to `'static` is
+        // sound only because `owned` is never moved while `completion` is
+        // alive, and because `ReadAt` declares `completion` before `owned`:
+        // struct fields drop in declaration order, so if this future is
+        // dropped before completing, `completion` (and whatever wait the
+        // kernel still needs to do for the in-flight SQE) is dropped before
+        // the buffer/file it points at are freed.
+        let mut owned = Box::new((file, buf));
+        let completion = unsafe {
+            let (file, buf): &mut (File, BytesMut) = &mut *owned;
+            mem::transmute::<_, rio::Completion<'static, usize>>(
+                self.rio.read_at(file, buf, offset),
+            )
+        };
+        ReadAt {
+            completion: Some(completion),
+            owned: Some(owned),
+        }
+    }
+
+    /// Submits a `write_at` SQE for `file` at `offset`, writing `buf`.
+    ///
+    /// Resolves to `file` handed back once the write completes.
+    pub(crate) fn write_at(&self, file: File, buf: Bytes, offset: u64) -> WriteAt {
+        // See the safety note in `read_at`: `owned` is the stable address the
+        // erased-lifetime completion actually points at, and `completion`
+        // must drop before `owned` does.
+        let mut owned = Box::new((file, buf));
+        let completion = unsafe {
+            let (file, buf): &mut (File, Bytes) = &mut *owned;
+            mem::transmute::<_, rio::Completion<'static, usize>>(
+                self.rio.write_at(file, buf, offset),
+            )
+        };
+        WriteAt {
+            completion: Some(completion),
+            owned: Some(owned),
+        }
+    }
+}
+
+impl ::std::fmt::Debug for Ring {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        f.debug_struct("Ring").finish()
+    }
+}
+
+/// A `Future` resolving to `(File, BytesMut)` once a submitted `read_at`
+/// SQE completes.
+///
+/// `rio::Completion` is a `std::future::Future`, not a futures-0.1 one, so
+/// there's no `Poll`-based bridge between the two without pulling in a
+/// compatibility shim; instead the first `poll` blocks the calling thread
+/// on `Completion::wait`, which resolves as soon as the kernel completes
+/// the SQE. Declaration order matters here: `completion` must be dropped
+/// before `owned` (see the safety note in `Ring::read_at`).
+pub(crate) struct ReadAt {
+    completion: Option<rio::Completion<'static, usize>>,
+    owned: Option<Box<(File, BytesMut)>>,
+}
+
+impl Future for ReadAt {
+    type Item = (File, BytesMut);
+    type Error = io::Error;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        let n = self.completion
+            .take()
+            .expect("ReadAt polled after Ready")
+            .wait()?;
+        let (file, mut buf) = *self.owned.take().expect("ReadAt polled after Ready");
+        unsafe { buf.advance_mut(n) };
+        Ok(::futures::Async::Ready((file, buf)))
+    }
+}
+
+/// A `Future` resolving to `File` once a submitted `write_at` SQE completes.
+///
+/// See `ReadAt` for why `poll` blocks on `Completion::wait`, and why
+/// `completion` is declared before `owned`.
+pub(crate) struct WriteAt {
+    completion: Option<rio::Completion<'static, usize>>,
+    owned: Option<Box<(File, Bytes)>>,
+}
+
+impl Future for WriteAt {
+    type Item = File;
+    type Error = io::Error;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        self.completion
+            .take()
+            .expect("WriteAt polled after Ready")
+            .wait()?;
+        let (file, _buf) = *self.owned.take().expect("WriteAt polled after Ready");
+        Ok(::futures::Async::Ready(file))
+    }
+}
@@ -2,8 +2,9 @@ extern crate futures;
 extern crate futures_fs;
 
 use std::{env, io, fs};
+use std::io::SeekFrom;
 use futures::{Future, Sink, Stream};
-use futures_fs::FsPool;
+use futures_fs::{FsPool, ReadOptions, WriteOptions};
 
 
 #[test]
@@ -118,3 +119,184 @@ fn test_from_file_smoke_long() {
 
     fs.delete(tmp).wait().unwrap();
 }
+
+
+#[test]
+fn test_ranged_read() {
+    let fs = FsPool::default();
+
+    let mut tmp = env::temp_dir();
+    tmp.push("futures-fs-ranged");
+    fs::write(&tmp, b"hello world").unwrap();
+
+    let data = fs.read(tmp.clone(), ReadOptions::default().offset(6).length(5))
+        .collect()
+        .wait()
+        .unwrap()
+        .concat();
+    assert_eq!(data, b"world");
+
+    fs.delete(tmp).wait().unwrap();
+}
+
+#[test]
+fn test_seek_read() {
+    let fs = FsPool::default();
+
+    let mut tmp = env::temp_dir();
+    tmp.push("futures-fs-seek");
+    fs::write(&tmp, b"hello world").unwrap();
+
+    // a length bigger than the file, so the first chunk rests in a
+    // non-EOF state and seek has a live file to reposition
+    let mut stream = fs.read(tmp.clone(), ReadOptions::default().length(100));
+
+    let first = stream.by_ref().take(1).collect().wait().unwrap().concat();
+    assert_eq!(first, b"hello world");
+
+    stream.seek(SeekFrom::Start(6));
+
+    let second = stream.collect().wait().unwrap().concat();
+    assert_eq!(second, b"world");
+
+    fs.delete(tmp).wait().unwrap();
+}
+
+#[test]
+fn test_sync() {
+    let fs = FsPool::default();
+
+    let mut tmp = env::temp_dir();
+    tmp.push("futures-fs-sync");
+
+    let sink = fs.write(tmp.clone(), Default::default());
+    sink.send("hello world".into())
+        .wait()
+        .unwrap()
+        .sync()
+        .wait()
+        .unwrap();
+
+    let data = fs.read(tmp.clone(), Default::default())
+        .collect()
+        .wait()
+        .unwrap()
+        .concat();
+    assert_eq!(data, b"hello world");
+
+    fs.delete(tmp).wait().unwrap();
+}
+
+#[test]
+fn test_sync_all_and_sync_data() {
+    let fs = FsPool::default();
+
+    let mut tmp = env::temp_dir();
+    tmp.push("futures-fs-sync-pool");
+    fs::write(&tmp, b"hello world").unwrap();
+
+    let file = fs::File::open(&tmp).unwrap();
+    fs.sync_all(file).wait().unwrap();
+
+    let file = fs::File::open(&tmp).unwrap();
+    fs.sync_data(file).wait().unwrap();
+
+    fs.delete(tmp).wait().unwrap();
+}
+
+#[test]
+fn test_reserve() {
+    let fs = FsPool::default();
+
+    let mut tmp = env::temp_dir();
+    tmp.push("futures-fs-reserve");
+
+    let opts = WriteOptions::default().reserve(4096);
+    fs.write(tmp.clone(), opts)
+        .send("hello".into())
+        .wait()
+        .unwrap();
+
+    // the sink never truncates, so the file is left at the reserved size,
+    // with the unwritten tail zero-filled
+    let metadata = fs::metadata(&tmp).unwrap();
+    assert_eq!(metadata.len(), 4096);
+
+    let data = fs::read(&tmp).unwrap();
+    assert_eq!(&data[..5], b"hello");
+    assert!(data[5..].iter().all(|&b| b == 0));
+
+    fs.delete(tmp).wait().unwrap();
+}
+
+#[test]
+fn test_metadata() {
+    let fs = FsPool::default();
+
+    let mut tmp = env::temp_dir();
+    tmp.push("futures-fs-metadata");
+    fs::write(&tmp, b"hello world").unwrap();
+
+    let metadata = fs.metadata(tmp.clone()).wait().unwrap();
+    assert_eq!(metadata.len(), 11);
+
+    fs.delete(tmp).wait().unwrap();
+}
+
+#[test]
+fn test_read_into_bytes() {
+    let fs = FsPool::default();
+
+    let mut tmp = env::temp_dir();
+    tmp.push("futures-fs-read-into-bytes");
+    fs::write(&tmp, b"hello world").unwrap();
+
+    let bytes = fs.read_into_bytes(tmp.clone(), ReadOptions::default())
+        .wait()
+        .unwrap();
+    assert_eq!(&bytes[..], b"hello world");
+
+    let bytes = fs.read_into_bytes(tmp.clone(), ReadOptions::default().offset(6))
+        .wait()
+        .unwrap();
+    assert_eq!(&bytes[..], b"world");
+
+    fs.delete(tmp).wait().unwrap();
+}
+
+#[test]
+fn test_dir_ops() {
+    let fs = FsPool::default();
+
+    let mut dir = env::temp_dir();
+    dir.push("futures-fs-dir");
+    fs.create_dir_all(dir.clone()).wait().unwrap();
+
+    let mut original = dir.clone();
+    original.push("original.txt");
+    fs::write(&original, b"hello world").unwrap();
+
+    let mut copied = dir.clone();
+    copied.push("copied.txt");
+    let n = fs.copy(original.clone(), copied.clone()).wait().unwrap();
+    assert_eq!(n, 11);
+
+    let mut renamed = dir.clone();
+    renamed.push("renamed.txt");
+    fs.rename(copied, renamed.clone()).wait().unwrap();
+
+    let names = fs.read_dir(dir.clone())
+        .collect()
+        .wait()
+        .unwrap()
+        .into_iter()
+        .map(|entry| entry.file_name().into_string().unwrap())
+        .collect::<Vec<_>>();
+
+    assert!(names.contains(&"original.txt".to_string()));
+    assert!(names.contains(&"renamed.txt".to_string()));
+
+    fs.delete(original).wait().unwrap();
+    fs.delete(renamed).wait().unwrap();
+    fs::remove_dir(dir).unwrap();
+}